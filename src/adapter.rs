@@ -0,0 +1,144 @@
+//! Adapters bridging legacy allocator traits to the nightly [`Allocator`] trait.
+
+use core::alloc::{AllocError, Allocator, GlobalAlloc, Layout};
+use core::ptr::{self, NonNull};
+
+/// Wraps a stable [`GlobalAlloc`] implementor so it can be used as a nightly [`Allocator`], and
+/// therefore erased behind a [`PolyAllocator`].
+///
+/// `GlobalAlloc` implementors are almost always `Copy`/`Clone` zero-sized types, so the adapter
+/// is itself `Clone` and slots directly into [`PolyAllocator::owned`].
+///
+/// [`PolyAllocator`]: crate::allocator::PolyAllocator
+/// [`PolyAllocator::owned`]: crate::allocator::PolyAllocator::owned
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlobalAllocAdapter<G>(pub G);
+
+impl<G> GlobalAllocAdapter<G> {
+    /// Wrap a [`GlobalAlloc`] implementor.
+    pub const fn new(inner: G) -> Self {
+        Self(inner)
+    }
+}
+
+impl<G: GlobalAlloc> GlobalAllocAdapter<G> {
+    /// The dangling pointer handed back for zero-sized requests, aligned to `layout`.
+    fn dangling(layout: Layout) -> NonNull<u8> {
+        // The alignment is a non-zero power of two, so this is never null.
+        NonNull::new(layout.align() as *mut u8).expect("layout alignment is non-zero")
+    }
+}
+
+/// SAFETY: we forward every method to the wrapped [`GlobalAlloc`], preserving the block layouts
+///         it was handed and honouring the zero-size convention of the [`Allocator`] trait.
+unsafe impl<G: GlobalAlloc> Allocator for GlobalAllocAdapter<G> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() == 0 {
+            return Ok(NonNull::slice_from_raw_parts(Self::dangling(layout), 0));
+        }
+        // SAFETY: `layout` has non-zero size, as `GlobalAlloc::alloc` requires.
+        let ptr = NonNull::new(unsafe { self.0.alloc(layout) }).ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() == 0 {
+            return Ok(NonNull::slice_from_raw_parts(Self::dangling(layout), 0));
+        }
+        // SAFETY: `layout` has non-zero size, as `GlobalAlloc::alloc_zeroed` requires.
+        let ptr = NonNull::new(unsafe { self.0.alloc_zeroed(layout) }).ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if layout.size() == 0 {
+            return;
+        }
+        // SAFETY: `ptr` was produced by this allocator for `layout`, which the caller upholds.
+        unsafe { self.0.dealloc(ptr.as_ptr(), layout) }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        // SAFETY: the caller upholds the `grow` contract.
+        unsafe { self.realloc(ptr, old_layout, new_layout, false) }
+    }
+
+    unsafe fn grow_zeroed(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        // SAFETY: the caller upholds the `grow_zeroed` contract.
+        unsafe { self.realloc(ptr, old_layout, new_layout, true) }
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        // SAFETY: the caller upholds the `shrink` contract.
+        unsafe { self.realloc(ptr, old_layout, new_layout, false) }
+    }
+}
+
+impl<G: GlobalAlloc> GlobalAllocAdapter<G> {
+    /// Shared implementation of `grow`/`grow_zeroed`/`shrink`.
+    ///
+    /// `GlobalAlloc::realloc` can only resize a block in place when the alignment is unchanged and
+    /// both the old and the new size are non-zero, so any other case falls back to an
+    /// allocate-copy-free.
+    /// When `zeroed` is set, the bytes past `old_layout.size()` are zeroed to honour
+    /// `grow_zeroed`.
+    /// SAFETY: `ptr`/`old_layout` name a live block of this allocator and the caller satisfies the
+    ///         size relationship required by the calling method.
+    unsafe fn realloc(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+        zeroed: bool,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if old_layout.align() == new_layout.align()
+            && old_layout.size() != 0
+            && new_layout.size() != 0
+        {
+            // SAFETY: alignment is preserved and both the old and new sizes are non-zero, as
+            //         `GlobalAlloc::realloc` requires.
+            let ptr = NonNull::new(unsafe {
+                self.0.realloc(ptr.as_ptr(), old_layout, new_layout.size())
+            })
+            .ok_or(AllocError)?;
+            if zeroed && new_layout.size() > old_layout.size() {
+                // SAFETY: `realloc` returned a block of at least `new_layout.size()` bytes.
+                unsafe {
+                    ptr.as_ptr()
+                        .add(old_layout.size())
+                        .write_bytes(0, new_layout.size() - old_layout.size());
+                }
+            }
+            return Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()));
+        }
+
+        let new_block = if zeroed {
+            self.allocate_zeroed(new_layout)?
+        } else {
+            self.allocate(new_layout)?
+        };
+        let copy = old_layout.size().min(new_layout.size());
+        // SAFETY: both blocks are valid for `copy` bytes and do not overlap; the old block is then
+        //         released back to this allocator.
+        unsafe {
+            ptr::copy_nonoverlapping(ptr.as_ptr(), new_block.cast::<u8>().as_ptr(), copy);
+            self.deallocate(ptr, old_layout);
+        }
+        Ok(new_block)
+    }
+}
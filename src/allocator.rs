@@ -1,10 +1,33 @@
 use alloc::alloc::handle_alloc_error;
 use core::alloc::{AllocError, Allocator, Layout};
 use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
 use core::ptr::{self, NonNull};
 
 use crate::traits::*;
-use crate::vtable::RawPolyAllocVTable;
+use crate::vtable::{fits_inline, pack_inline, CLayout, CPolyAllocVTable, RawPolyAllocVTable};
+
+/// ABI-stable, `repr(C)` decomposition of a [`PolyAllocator`].
+///
+/// This is the layout a host hands to a dynamically loaded module: `data` is the erased
+/// allocator pointer and `vtable` its ABI-stable [`CPolyAllocVTable`]. A separately compiled
+/// consumer drives the allocator through `vtable` directly and never reconstructs a
+/// `PolyAllocator` (whose layout is not stable across compilation units).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawPolyAllocParts {
+    pub data: *mut (),
+    pub vtable: *const CPolyAllocVTable,
+}
+
+impl RawPolyAllocParts {
+    /// Allocate through the ABI-stable vtable — the entry point for a separately compiled module.
+    /// SAFETY: `self` must name a live allocator, as produced by [`PolyAllocator::into_c_raw_parts`].
+    pub unsafe fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        // SAFETY: `data` is a valid data pointer for `vtable` by the parts invariant.
+        unsafe { ((*self.vtable).allocate)(self.data, CLayout::from_layout(layout)) }.into_result()
+    }
+}
 
 /// A polymorphic allocator.
 #[derive(Debug)]
@@ -45,7 +68,9 @@ impl<Traits> Drop for PolyAllocator<'_, Traits> {
 
 impl<Traits> Clone for PolyAllocator<'_, Traits> {
     fn clone(&self) -> Self {
-        // SAFETY: We have a proper new data pointer from the clone method in the vtable
+        // SAFETY: We have a proper new data pointer from the clone method in the vtable, which
+        // aborts (via `handle_alloc_error` with the backing layout) if the clone cannot allocate.
+        // Callers that want to handle that failure should use [`Self::try_clone`] instead.
         unsafe { Self::from_raw_parts((self.vtable.clone)(self.data.as_ptr()), self.vtable) }
     }
 }
@@ -63,13 +88,58 @@ impl<'a, Traits> PolyAllocator<'a, Traits> {
     }
 
     pub fn into_raw_parts(self) -> (NonNull<()>, &'static RawPolyAllocVTable) {
-        (self.data, self.vtable)
+        let this = ManuallyDrop::new(self);
+        (this.data, this.vtable)
+    }
+
+    /// Decompose into an ABI-stable [`RawPolyAllocParts`] for hand-off across a dynamic library
+    /// boundary. The returned parts borrow nothing; the caller owns the erased allocator and is
+    /// responsible for eventually deleting it through the vtable or re-importing it with
+    /// [`Self::from_c_raw_parts`].
+    pub fn into_c_raw_parts(self) -> RawPolyAllocParts {
+        let this = ManuallyDrop::new(self);
+        RawPolyAllocParts {
+            data: this.data.as_ptr(),
+            vtable: this.vtable.c,
+        }
+    }
+
+    /// Reconstruct from ABI-stable parts within a compilation unit that still holds the Rust
+    /// `vtable` twin for the same allocator type.
+    /// SAFETY: `parts` must originate from [`Self::into_c_raw_parts`] and `vtable` must be the
+    ///         Rust twin of `parts.vtable`; the underlying allocator must live for `'a`.
+    pub unsafe fn from_c_raw_parts(
+        parts: RawPolyAllocParts,
+        vtable: &'static RawPolyAllocVTable,
+    ) -> Self {
+        // SAFETY: `parts.data` is the non-null data pointer produced by `into_c_raw_parts`.
+        unsafe { Self::from_raw_parts(NonNull::new_unchecked(parts.data), vtable) }
+    }
+
+    /// Clone the allocator, returning an error instead of aborting if the clone's backing
+    /// storage could not be allocated. The borrowed case never allocates and so never fails.
+    pub fn try_clone(&self) -> Result<Self, AllocError> {
+        // SAFETY: The vtable's `try_clone` yields a valid data pointer for a fresh clone, or an
+        // error if allocation failed; we pair it with the same vtable.
+        unsafe {
+            let data = (self.vtable.try_clone)(self.data.as_ptr())?;
+            Ok(Self::from_raw_parts(data, self.vtable))
+        }
     }
 
     fn try_owned_internal<A>(allocator: A) -> Result<Self, AllocError>
     where
         A: Allocator + Clone + 'a,
     {
+        if const { fits_inline::<A>() } {
+            // SAFETY: `A` fits inline by the const check; the inline vtable matches the packing.
+            return Ok(unsafe {
+                Self::from_raw_parts(
+                    pack_inline(allocator),
+                    RawPolyAllocVTable::inline_owned::<A>(),
+                )
+            });
+        }
         let layout = Layout::new::<A>();
         let storage = allocator.allocate(layout)?.cast::<A>();
         // SAFETY: `storage` points to allocated memory for type `A`, which the generic
@@ -93,6 +163,56 @@ impl<'a, Traits> PolyAllocator<'a, Traits> {
         }
     }
 
+    fn try_owning_internal<A>(allocator: A) -> Result<Self, AllocError>
+    where
+        A: OwningAllocator + Clone + 'a,
+    {
+        if const { fits_inline::<A>() } {
+            // SAFETY: `A` fits inline by the const check; the inline vtable matches the packing.
+            return Ok(unsafe {
+                Self::from_raw_parts(
+                    pack_inline(allocator),
+                    RawPolyAllocVTable::inline_owning::<A>(),
+                )
+            });
+        }
+        let layout = Layout::new::<A>();
+        let storage = allocator.allocate(layout)?.cast::<A>();
+        // SAFETY: `storage` points to allocated memory for type `A`, which the generic
+        //         bounds guarantee lives for `'a`.
+        unsafe {
+            ptr::write(storage.as_ptr(), allocator);
+            Ok(Self::from_raw_parts(
+                storage.cast::<()>(),
+                RawPolyAllocVTable::owning::<A>(),
+            ))
+        }
+    }
+
+    fn owning_internal<A>(allocator: A) -> Self
+    where
+        A: OwningAllocator + Clone + 'a,
+    {
+        match Self::try_owning_internal(allocator) {
+            Ok(ret) => ret,
+            Err(_) => handle_alloc_error(Layout::new::<A>()),
+        }
+    }
+
+    fn owning_borrowed_internal<A>(allocator: &'a A) -> Self
+    where
+        A: OwningAllocator + 'a,
+    {
+        // SAFETY: The vtable is compatible with `A` in a borrowed context, and we borrow
+        //         the allocator for `'a`.
+        unsafe {
+            Self::from_raw_parts(
+                NonNull::from(allocator).cast::<()>(),
+                RawPolyAllocVTable::owning_borrowed::<A>(),
+            )
+        }
+    }
+
     fn borrowed_internal<A>(allocator: &'a A) -> Self
     where
         A: Allocator + 'a,
@@ -133,6 +253,32 @@ impl<'a> PolyAllocator<'a, LocalTrait> {
     {
         Self::borrowed_internal(allocator)
     }
+
+    /// Construct a polymorphic allocator from an [`OwningAllocator`], preserving its ability to
+    /// answer ownership queries after erasure. Returns an error if storage could not be allocated.
+    pub fn try_owning<A>(allocator: A) -> Result<Self, AllocError>
+    where
+        A: OwningAllocator + Clone + 'a,
+    {
+        Self::try_owning_internal(allocator)
+    }
+
+    /// Construct a polymorphic allocator from an [`OwningAllocator`], preserving its ability to
+    /// answer ownership queries after erasure.
+    pub fn owning<A>(allocator: A) -> Self
+    where
+        A: OwningAllocator + Clone + 'a,
+    {
+        Self::owning_internal(allocator)
+    }
+
+    /// Construct a polymorphic allocator from a borrow of an [`OwningAllocator`].
+    pub fn owning_borrowed<A>(allocator: &'a A) -> Self
+    where
+        A: OwningAllocator + 'a,
+    {
+        Self::owning_borrowed_internal(allocator)
+    }
 }
 
 impl<'a> PolyAllocator<'a, SendTrait> {
@@ -160,6 +306,32 @@ impl<'a> PolyAllocator<'a, SendTrait> {
     {
         Self::borrowed_internal(allocator)
     }
+
+    /// Construct a polymorphic allocator from an [`OwningAllocator`], preserving its ability to
+    /// answer ownership queries after erasure. Returns an error if storage could not be allocated.
+    pub fn try_owning<A>(allocator: A) -> Result<Self, AllocError>
+    where
+        A: OwningAllocator + Clone + Send + 'a,
+    {
+        Self::try_owning_internal(allocator)
+    }
+
+    /// Construct a polymorphic allocator from an [`OwningAllocator`], preserving its ability to
+    /// answer ownership queries after erasure.
+    pub fn owning<A>(allocator: A) -> Self
+    where
+        A: OwningAllocator + Clone + Send + 'a,
+    {
+        Self::owning_internal(allocator)
+    }
+
+    /// Construct a polymorphic allocator from a borrow of an [`OwningAllocator`].
+    pub fn owning_borrowed<A>(allocator: &'a A) -> Self
+    where
+        A: OwningAllocator + Sync + 'a,
+    {
+        Self::owning_borrowed_internal(allocator)
+    }
 }
 
 impl<'a> PolyAllocator<'a, SendSyncTrait> {
@@ -187,6 +359,32 @@ impl<'a> PolyAllocator<'a, SendSyncTrait> {
     {
         Self::borrowed_internal(allocator)
     }
+
+    /// Construct a polymorphic allocator from an [`OwningAllocator`], preserving its ability to
+    /// answer ownership queries after erasure. Returns an error if storage could not be allocated.
+    pub fn try_owning<A>(allocator: A) -> Result<Self, AllocError>
+    where
+        A: OwningAllocator + Clone + Send + Sync + 'a,
+    {
+        Self::try_owning_internal(allocator)
+    }
+
+    /// Construct a polymorphic allocator from an [`OwningAllocator`], preserving its ability to
+    /// answer ownership queries after erasure.
+    pub fn owning<A>(allocator: A) -> Self
+    where
+        A: OwningAllocator + Clone + Send + Sync + 'a,
+    {
+        Self::owning_internal(allocator)
+    }
+
+    /// Construct a polymorphic allocator from a borrow of an [`OwningAllocator`].
+    pub fn owning_borrowed<A>(allocator: &'a A) -> Self
+    where
+        A: OwningAllocator + Sync + 'a,
+    {
+        Self::owning_borrowed_internal(allocator)
+    }
 }
 
 /// SAFETY: we forward all method impls to the underlying allocator.
@@ -230,3 +428,11 @@ unsafe impl<Traits> Allocator for PolyAllocator<'_, Traits> {
         unsafe { (self.vtable.shrink)(self.data.as_ptr(), ptr, old_layout, new_layout) }
     }
 }
+
+/// SAFETY: we forward to the ownership query installed in the vtable. Allocators erased through
+///         the non-owning constructors answer conservatively (see [`RawPolyAllocVTable::owned`]).
+unsafe impl<Traits> OwningAllocator for PolyAllocator<'_, Traits> {
+    fn owns(&self, ptr: NonNull<u8>, layout: Layout) -> bool {
+        unsafe { (self.vtable.owns)(self.data.as_ptr(), ptr, layout) }
+    }
+}
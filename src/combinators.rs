@@ -0,0 +1,315 @@
+//! Composable allocator combinators.
+//!
+//! Each combinator is itself an [`Allocator`], so chains can be nested and then erased behind a
+//! [`PolyAllocator`]. Combinators that wrap [`OwningAllocator`]s are themselves [`OwningAllocator`]s,
+//! which is what lets a [`Fallback`] route a deallocation back to the link that produced it.
+//!
+//! [`PolyAllocator`]: crate::allocator::PolyAllocator
+
+use core::alloc::{AllocError, Allocator, Layout};
+use core::ptr::{self, NonNull};
+
+use crate::traits::OwningAllocator;
+
+/// An allocator that never succeeds. Every allocation returns [`AllocError`], and deallocation
+/// is a contract violation because no block can originate here.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullAllocator;
+
+/// SAFETY: `allocate` never returns a block, so the deallocation obligations are vacuous.
+unsafe impl Allocator for NullAllocator {
+    fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Err(AllocError)
+    }
+
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {
+        panic!("NullAllocator cannot deallocate a block it never allocated");
+    }
+}
+
+/// SAFETY: the null allocator owns nothing.
+unsafe impl OwningAllocator for NullAllocator {
+    fn owns(&self, _ptr: NonNull<u8>, _layout: Layout) -> bool {
+        false
+    }
+}
+
+/// Tries `primary` first and falls back to `secondary` on [`AllocError`].
+///
+/// `primary` must be an [`OwningAllocator`] so that deallocation, growth, and shrinking can be
+/// routed to the link that actually produced the block. If `primary` cannot answer ownership
+/// precisely (a `Global`-style allocator), it must *not* be used as the primary: its conservative
+/// `owns` would claim blocks that really belong to `secondary`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Fallback<P, S> {
+    /// The allocator tried first.
+    pub primary: P,
+    /// The allocator used when `primary` fails.
+    pub secondary: S,
+}
+
+impl<P, S> Fallback<P, S> {
+    /// Construct a fallback chain from its two links.
+    pub const fn new(primary: P, secondary: S) -> Self {
+        Self { primary, secondary }
+    }
+}
+
+/// SAFETY: we forward to the owning link for every block-consuming method, and `primary` reports
+///         ownership precisely, so no block is ever handed to the wrong allocator.
+unsafe impl<P, S> Allocator for Fallback<P, S>
+where
+    P: OwningAllocator,
+    S: Allocator,
+{
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.primary
+            .allocate(layout)
+            .or_else(|_| self.secondary.allocate(layout))
+    }
+
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.primary
+            .allocate_zeroed(layout)
+            .or_else(|_| self.secondary.allocate_zeroed(layout))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // SAFETY: the block belongs to whichever link claims it, and the caller upholds the
+        //         deallocation contract for that link.
+        unsafe {
+            if self.primary.owns(ptr, layout) {
+                self.primary.deallocate(ptr, layout);
+            } else {
+                self.secondary.deallocate(ptr, layout);
+            }
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        // SAFETY: routed to the owning link, whose grow contract the caller upholds.
+        unsafe {
+            if self.primary.owns(ptr, old_layout) {
+                self.primary.grow(ptr, old_layout, new_layout)
+            } else {
+                self.secondary.grow(ptr, old_layout, new_layout)
+            }
+        }
+    }
+
+    unsafe fn grow_zeroed(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        // SAFETY: routed to the owning link, whose grow contract the caller upholds.
+        unsafe {
+            if self.primary.owns(ptr, old_layout) {
+                self.primary.grow_zeroed(ptr, old_layout, new_layout)
+            } else {
+                self.secondary.grow_zeroed(ptr, old_layout, new_layout)
+            }
+        }
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        // SAFETY: routed to the owning link, whose shrink contract the caller upholds.
+        unsafe {
+            if self.primary.owns(ptr, old_layout) {
+                self.primary.shrink(ptr, old_layout, new_layout)
+            } else {
+                self.secondary.shrink(ptr, old_layout, new_layout)
+            }
+        }
+    }
+}
+
+/// SAFETY: the chain owns a block iff one of its links owns it.
+unsafe impl<P, S> OwningAllocator for Fallback<P, S>
+where
+    P: OwningAllocator,
+    S: OwningAllocator,
+{
+    fn owns(&self, ptr: NonNull<u8>, layout: Layout) -> bool {
+        self.primary.owns(ptr, layout) || self.secondary.owns(ptr, layout)
+    }
+}
+
+/// Routes requests of at most `N` bytes to `small` and everything larger to `large`.
+///
+/// The size threshold is deterministic, so `deallocate`/`grow`/`shrink` pick the target link from
+/// the block's layout without consulting ownership.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Segregator<const N: usize, Small, Large> {
+    /// The allocator used for requests of at most `N` bytes.
+    pub small: Small,
+    /// The allocator used for requests larger than `N` bytes.
+    pub large: Large,
+}
+
+impl<const N: usize, Small, Large> Segregator<N, Small, Large> {
+    /// Construct a segregator from its two allocators.
+    pub const fn new(small: Small, large: Large) -> Self {
+        Self { small, large }
+    }
+
+    /// Returns `true` if a request with `layout` is routed to `small`.
+    const fn uses_small(layout: Layout) -> bool {
+        layout.size() <= N
+    }
+}
+
+/// SAFETY: the routing function is a pure function of `layout`, so a block is always returned to
+///         the link that produced it.
+unsafe impl<const N: usize, Small, Large> Allocator for Segregator<N, Small, Large>
+where
+    Small: Allocator,
+    Large: Allocator,
+{
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if Self::uses_small(layout) {
+            self.small.allocate(layout)
+        } else {
+            self.large.allocate(layout)
+        }
+    }
+
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if Self::uses_small(layout) {
+            self.small.allocate_zeroed(layout)
+        } else {
+            self.large.allocate_zeroed(layout)
+        }
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // SAFETY: the layout selects the producing link, and the caller upholds its contract.
+        unsafe {
+            if Self::uses_small(layout) {
+                self.small.deallocate(ptr, layout);
+            } else {
+                self.large.deallocate(ptr, layout);
+            }
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        // A grow may move a block across the size threshold; fall back to allocate-and-copy in
+        // that case so the new block lives in the correct link.
+        if Self::uses_small(old_layout) == Self::uses_small(new_layout) {
+            // SAFETY: both layouts route to the same link, whose grow contract the caller upholds.
+            unsafe {
+                if Self::uses_small(new_layout) {
+                    self.small.grow(ptr, old_layout, new_layout)
+                } else {
+                    self.large.grow(ptr, old_layout, new_layout)
+                }
+            }
+        } else {
+            let new_ptr = self.allocate(new_layout)?;
+            // SAFETY: `new_ptr` is at least `new_layout.size()` long and `old_layout.size()` is no
+            //         larger, and `ptr` is valid for `old_layout` by the caller's contract.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    ptr.as_ptr(),
+                    new_ptr.cast::<u8>().as_ptr(),
+                    old_layout.size(),
+                );
+                self.deallocate(ptr, old_layout);
+            }
+            Ok(new_ptr)
+        }
+    }
+
+    unsafe fn grow_zeroed(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if Self::uses_small(old_layout) == Self::uses_small(new_layout) {
+            // SAFETY: both layouts route to the same link, whose grow contract the caller upholds.
+            unsafe {
+                if Self::uses_small(new_layout) {
+                    self.small.grow_zeroed(ptr, old_layout, new_layout)
+                } else {
+                    self.large.grow_zeroed(ptr, old_layout, new_layout)
+                }
+            }
+        } else {
+            let new_ptr = self.allocate_zeroed(new_layout)?;
+            // SAFETY: see `grow`; the tail past `old_layout.size()` is already zeroed.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    ptr.as_ptr(),
+                    new_ptr.cast::<u8>().as_ptr(),
+                    old_layout.size(),
+                );
+                self.deallocate(ptr, old_layout);
+            }
+            Ok(new_ptr)
+        }
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if Self::uses_small(old_layout) == Self::uses_small(new_layout) {
+            // SAFETY: both layouts route to the same link, whose shrink contract the caller upholds.
+            unsafe {
+                if Self::uses_small(new_layout) {
+                    self.small.shrink(ptr, old_layout, new_layout)
+                } else {
+                    self.large.shrink(ptr, old_layout, new_layout)
+                }
+            }
+        } else {
+            let new_ptr = self.allocate(new_layout)?;
+            // SAFETY: `new_layout.size() <= old_layout.size()` for a shrink, and `ptr` is valid
+            //         for `old_layout`.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    ptr.as_ptr(),
+                    new_ptr.cast::<u8>().as_ptr(),
+                    new_layout.size(),
+                );
+                self.deallocate(ptr, old_layout);
+            }
+            Ok(new_ptr)
+        }
+    }
+}
+
+/// SAFETY: the layout selects the owning link, which is asked in turn.
+unsafe impl<const N: usize, Small, Large> OwningAllocator for Segregator<N, Small, Large>
+where
+    Small: OwningAllocator,
+    Large: OwningAllocator,
+{
+    fn owns(&self, ptr: NonNull<u8>, layout: Layout) -> bool {
+        if Self::uses_small(layout) {
+            self.small.owns(ptr, layout)
+        } else {
+            self.large.owns(ptr, layout)
+        }
+    }
+}
@@ -1,10 +1,15 @@
 #![feature(allocator_api)]
 #![forbid(unsafe_op_in_unsafe_fn)]
+// Most safety preconditions are documented with `/// SAFETY:` lines rather than clippy's
+// `# Safety` doc section, so silence the lint that only recognises the latter.
+#![allow(clippy::missing_safety_doc)]
 #![no_std]
 
 extern crate alloc;
 
+pub mod adapter;
 pub mod allocator;
+pub mod combinators;
 pub mod traits;
 pub mod vtable;
 
@@ -35,4 +40,17 @@ mod tests {
         // let allocator = PolyAllocator::new(Global);
         // let v = Box::new_in(3, allocator);
     }
+
+    #[test]
+    fn inline_storage_round_trips() {
+        // `Global` is a ZST, so it is stored inline in the data word: allocating, cloning, and
+        // dropping must all work without a backing heap allocation for the allocator itself.
+        let allocator = SharedPolyAllocator::owned(Global);
+        let mut v = Vec::new_in(allocator.clone());
+        v.extend_from_slice(&[1, 2, 3, 4]);
+        v.push(5);
+        assert_eq!(v.len(), 5);
+        drop(allocator);
+        drop(v);
+    }
 }
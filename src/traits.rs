@@ -1,5 +1,25 @@
+use core::alloc::{Allocator, Layout};
 use core::cell::Cell;
 use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/// An [`Allocator`] that can report whether it is responsible for a given block of memory.
+///
+/// This is the extension point combinators such as [`Fallback`] use to route a `deallocate`,
+/// `grow`, or `shrink` to the allocator that actually handed out the block.
+///
+/// # Safety
+/// `owns` must return `true` for every block currently live that was produced by `self`. A
+/// precise implementation does not claim a block produced by an unrelated allocator. A
+/// conservative one (such as a [`PolyAllocator`] erased through a non-owning constructor) returns
+/// `true` for every block, and is then only sound as the last link of a [`Fallback`] chain.
+///
+/// [`Fallback`]: crate::combinators::Fallback
+/// [`PolyAllocator`]: crate::allocator::PolyAllocator
+pub unsafe trait OwningAllocator: Allocator {
+    /// Returns `true` if `ptr`/`layout` denotes a block currently allocated by `self`.
+    fn owns(&self, ptr: NonNull<u8>, layout: Layout) -> bool;
+}
 
 /// Marker for allocators that are neither Send nor Sync.
 pub struct LocalTrait(PhantomData<&'static Cell<()>>);
@@ -1,6 +1,9 @@
+use core::mem;
 use core::ptr::{self, NonNull};
 use std::alloc::{self, AllocError, Allocator, Layout};
 
+use crate::traits::OwningAllocator;
+
 /// Allocator trait vtable struct.
 /// SAFETY: All functions must be called using a valid data pointer for the type
 /// represented in this vtable.
@@ -15,8 +18,105 @@ pub struct RawPolyAllocVTable {
         unsafe fn(*const (), NonNull<u8>, Layout, Layout) -> Result<NonNull<[u8]>, AllocError>,
     pub shrink:
         unsafe fn(*const (), NonNull<u8>, Layout, Layout) -> Result<NonNull<[u8]>, AllocError>,
+    pub owns: unsafe fn(*const (), NonNull<u8>, Layout) -> bool,
     pub delete: unsafe fn(NonNull<()>),
     pub clone: unsafe fn(*const ()) -> NonNull<()>,
+    pub try_clone: unsafe fn(*const ()) -> Result<NonNull<()>, AllocError>,
+    /// The ABI-stable twin of this vtable, used when an erased allocator is handed across a
+    /// dynamic library boundary. See [`CPolyAllocVTable`].
+    pub c: &'static CPolyAllocVTable,
+}
+
+/// C-representable result of an allocation. A null `ptr` is the [`AllocError`] sentinel; on
+/// success `ptr` is the start of the block and `len` its usable length.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CAllocResult {
+    pub ptr: *mut u8,
+    pub len: usize,
+}
+
+impl CAllocResult {
+    /// The error sentinel.
+    const ERR: Self = Self {
+        ptr: ptr::null_mut(),
+        len: 0,
+    };
+
+    /// Wraps an [`Allocator`] result as its C-representable form.
+    pub fn from_result(result: Result<NonNull<[u8]>, AllocError>) -> Self {
+        match result {
+            Ok(block) => Self {
+                ptr: block.as_ptr().cast::<u8>(),
+                len: block.len(),
+            },
+            Err(AllocError) => Self::ERR,
+        }
+    }
+
+    /// Recovers an [`Allocator`] result, treating a null `ptr` as [`AllocError`].
+    pub fn into_result(self) -> Result<NonNull<[u8]>, AllocError> {
+        match NonNull::new(self.ptr) {
+            Some(ptr) => Ok(NonNull::slice_from_raw_parts(ptr, self.len)),
+            None => Err(AllocError),
+        }
+    }
+}
+
+/// C-representable form of a [`Layout`]. `Layout` is `repr(Rust)` with an unspecified layout, so
+/// it cannot cross an `extern "C"` boundary; the shims take this `repr(C)` pair and rebuild the
+/// `Layout` internally.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CLayout {
+    pub size: usize,
+    pub align: usize,
+}
+
+impl CLayout {
+    /// Flattens a [`Layout`] into its size and alignment.
+    pub fn from_layout(layout: Layout) -> Self {
+        Self {
+            size: layout.size(),
+            align: layout.align(),
+        }
+    }
+
+    /// Rebuilds the [`Layout`].
+    ///
+    /// # Safety
+    /// `self` must have been produced by [`from_layout`](Self::from_layout), so that `size` and
+    /// `align` form a valid `Layout`.
+    pub unsafe fn into_layout(self) -> Layout {
+        // SAFETY: the size/align came from a valid `Layout` by the precondition.
+        unsafe { Layout::from_size_align_unchecked(self.size, self.align) }
+    }
+}
+
+/// ABI-stable (`repr(C)`, `extern "C"`) variant of [`RawPolyAllocVTable`].
+///
+/// The vtable shims are monomorphized per backing allocator, so a vtable built in one compilation
+/// unit cannot be shared with a module compiled separately: the `extern "Rust"` calling
+/// convention and the layout of `Result<NonNull<[u8]>, AllocError>` are both unstable. This twin
+/// uses `extern "C"` shims and [`CAllocResult`] so a host can hand a type-erased allocator to a
+/// dynamically loaded module without the two sharing monomorphized code.
+///
+/// SAFETY: As with [`RawPolyAllocVTable`], every function must be called with a valid data
+/// pointer for the type represented in this vtable.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CPolyAllocVTable {
+    pub allocate: unsafe extern "C" fn(*const (), CLayout) -> CAllocResult,
+    pub allocate_zeroed: unsafe extern "C" fn(*const (), CLayout) -> CAllocResult,
+    pub deallocate: unsafe extern "C" fn(*const (), *mut u8, CLayout),
+    pub grow: unsafe extern "C" fn(*const (), *mut u8, CLayout, CLayout) -> CAllocResult,
+    pub grow_zeroed: unsafe extern "C" fn(*const (), *mut u8, CLayout, CLayout) -> CAllocResult,
+    pub shrink: unsafe extern "C" fn(*const (), *mut u8, CLayout, CLayout) -> CAllocResult,
+    pub owns: unsafe extern "C" fn(*const (), *mut u8, CLayout) -> bool,
+    pub delete: unsafe extern "C" fn(*mut ()),
+    pub clone: unsafe extern "C" fn(*const ()) -> *mut (),
+    /// Fallible clone. Returns a null pointer on allocation failure.
+    pub try_clone: unsafe extern "C" fn(*const ()) -> *mut (),
 }
 
 macro_rules! allocator_fwd {
@@ -39,6 +139,166 @@ allocator_fwd!(grow(ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) ->
 allocator_fwd!(grow_zeroed(ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError>);
 allocator_fwd!(shrink(ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError>);
 
+macro_rules! c_allocator_fwd {
+    ($cname:ident => $name:ident ($($param:ident : $typ:ty),*)) => {
+        pub unsafe extern "C" fn $cname<A>(this: *const (), $($param: $typ),*) -> CAllocResult
+        where
+            A: Allocator,
+        {
+            let this = this.cast::<A>();
+            // SAFETY: `this` points to a valid `A` by the vtable contract; `ptr` pointers are
+            //         non-null because they name live blocks.
+            let result = unsafe { (&*this).$name($(c_in!($param, $param)),*) };
+            CAllocResult::from_result(result)
+        }
+    };
+}
+
+/// Converts an incoming FFI argument into the type expected by the [`Allocator`] method: raw
+/// `*mut u8` block pointers become `NonNull<u8>`, [`CLayout`] arguments are rebuilt into `Layout`,
+/// and anything else passes through. The value is bound through `$v` so the identifier carries the
+/// shim's call-site context rather than the macro def-site (where `ptr` names the `core::ptr`
+/// module). Every arm is evaluated inside the enclosing shim's `unsafe` block, so no inner `unsafe`
+/// is needed here.
+macro_rules! c_in {
+    (ptr, $v:ident) => {
+        // SAFETY: a block pointer handed back for reuse is never null.
+        NonNull::new_unchecked($v)
+    };
+    (layout, $v:ident) => {
+        $v.into_layout()
+    };
+    (old_layout, $v:ident) => {
+        $v.into_layout()
+    };
+    (new_layout, $v:ident) => {
+        $v.into_layout()
+    };
+    ($other:ident, $v:ident) => {
+        $v
+    };
+}
+
+c_allocator_fwd!(c_allocate => allocate(layout: CLayout));
+c_allocator_fwd!(c_allocate_zeroed => allocate_zeroed(layout: CLayout));
+c_allocator_fwd!(c_grow => grow(ptr: *mut u8, old_layout: CLayout, new_layout: CLayout));
+c_allocator_fwd!(c_grow_zeroed => grow_zeroed(ptr: *mut u8, old_layout: CLayout, new_layout: CLayout));
+c_allocator_fwd!(c_shrink => shrink(ptr: *mut u8, old_layout: CLayout, new_layout: CLayout));
+
+/// `extern "C"` shim for `deallocate`.
+/// SAFETY: `this` points to a valid `A`, and `ptr`/`layout` name a block allocated by it.
+pub unsafe extern "C" fn c_deallocate<A>(this: *const (), ptr: *mut u8, layout: CLayout)
+where
+    A: Allocator,
+{
+    let this = this.cast::<A>();
+    // SAFETY: `ptr` names a live block, so it is non-null; `layout` came from a valid `Layout`.
+    unsafe {
+        let ptr = NonNull::new_unchecked(ptr);
+        (&*this).deallocate(ptr, layout.into_layout());
+    }
+}
+
+/// `extern "C"` shim for the conservative ownership query.
+pub extern "C" fn c_default_owns<A>(_this: *const (), _ptr: *mut u8, _layout: CLayout) -> bool
+where
+    A: Allocator,
+{
+    true
+}
+
+/// `extern "C"` shim forwarding an ownership query to the underlying [`OwningAllocator`].
+/// SAFETY: `this` must point to a value of type `A`.
+pub unsafe extern "C" fn c_owning_owns<A>(this: *const (), ptr: *mut u8, layout: CLayout) -> bool
+where
+    A: OwningAllocator,
+{
+    let this = unsafe { &*this.cast::<A>() };
+    // SAFETY: `ptr` names a candidate block, so it is non-null; `layout` came from a valid `Layout`.
+    this.owns(unsafe { NonNull::new_unchecked(ptr) }, unsafe {
+        layout.into_layout()
+    })
+}
+
+/// `extern "C"` shim for `default_delete`.
+/// SAFETY: as [`default_delete`].
+pub unsafe extern "C" fn c_default_delete<A>(this: *mut ())
+where
+    A: Allocator,
+{
+    // SAFETY: `this` is non-null and points to a valid `A` in its own storage.
+    unsafe { default_delete::<A>(NonNull::new_unchecked(this)) }
+}
+
+/// `extern "C"` shim for `ref_delete`.
+pub extern "C" fn c_ref_delete(_this: *mut ()) {}
+
+/// `extern "C"` shim for `default_clone`.
+/// SAFETY: as [`default_clone`].
+pub unsafe extern "C" fn c_default_clone<A>(this: *const ()) -> *mut ()
+where
+    A: Allocator + Clone,
+{
+    // SAFETY: `this` points to a valid `A`.
+    unsafe { default_clone::<A>(this).as_ptr() }
+}
+
+/// `extern "C"` shim for `ref_clone`.
+/// SAFETY: as [`ref_clone`].
+pub unsafe extern "C" fn c_ref_clone<A>(this: *const ()) -> *mut ()
+where
+    A: Allocator,
+{
+    // SAFETY: `this` points to a valid `A`.
+    unsafe { ref_clone::<A>(this).as_ptr() }
+}
+
+/// `extern "C"` shim for `default_try_clone`; returns a null pointer on allocation failure.
+/// SAFETY: as [`default_try_clone`].
+pub unsafe extern "C" fn c_default_try_clone<A>(this: *const ()) -> *mut ()
+where
+    A: Allocator + Clone,
+{
+    // SAFETY: `this` points to a valid `A`.
+    match unsafe { default_try_clone::<A>(this) } {
+        Ok(storage) => storage.as_ptr(),
+        Err(AllocError) => ptr::null_mut(),
+    }
+}
+
+/// `extern "C"` shim for `ref_try_clone`.
+/// SAFETY: as [`ref_try_clone`].
+pub unsafe extern "C" fn c_ref_try_clone<A>(this: *const ()) -> *mut ()
+where
+    A: Allocator,
+{
+    // SAFETY: `this` points to a valid `A`.
+    unsafe { ref_clone::<A>(this).as_ptr() }
+}
+
+/// Conservative ownership query for allocators that cannot track their own blocks.
+/// Returns `true` unconditionally, which is only sound when this allocator is the last link
+/// of a fallback chain. Allocators that can answer precisely supply an [`OwningAllocator`]
+/// impl and are erased through [`RawPolyAllocVTable::owning`] instead.
+///
+/// [`OwningAllocator`]: crate::traits::OwningAllocator
+pub fn default_owns<A>(_this: *const (), _ptr: NonNull<u8>, _layout: Layout) -> bool
+where
+    A: Allocator,
+{
+    true
+}
+
+/// Forwards an ownership query to the underlying [`OwningAllocator`].
+/// SAFETY: `this` must point to a value of type `A`.
+pub unsafe fn owning_owns<A>(this: *const (), ptr: NonNull<u8>, layout: Layout) -> bool
+where
+    A: OwningAllocator,
+{
+    let this = unsafe { &*this.cast::<A>() };
+    this.owns(ptr, layout)
+}
+
 /// Moves the allocator out of its place, deallocates the backing memory, and drops the
 /// allocator.
 /// SAFETY: `this` must be a pointer to an allocator of type `A`. Additionally, the memory
@@ -59,22 +319,43 @@ where
     println!("Dropped allocator!");
 }
 
-/// Clones the underlying allocator into a new allocation.
+/// Fallibly clones the underlying allocator into a new allocation, propagating an allocation
+/// failure instead of aborting.
 /// SAFETY: `this` must point to a value of type `A`.
-pub unsafe fn default_clone<A>(this: *const ()) -> NonNull<()>
+pub unsafe fn default_try_clone<A>(this: *const ()) -> Result<NonNull<()>, AllocError>
 where
     A: Allocator + Clone,
 {
     let this = unsafe { &*this.cast::<A>() };
     let layout = Layout::new::<A>();
-    let new_storage = match this.allocate(layout) {
-        Ok(storage) => storage.cast::<A>(),
-        Err(_) => alloc::handle_alloc_error(layout),
-    };
+    let new_storage = this.allocate(layout)?.cast::<A>();
     // SAFETY: we just allocated `new_storage` for a value of type `A`.
     unsafe {
         ptr::write(new_storage.as_ptr(), this.clone());
-        new_storage.cast::<()>()
+        Ok(new_storage.cast::<()>())
+    }
+}
+
+/// Fallible clone for shared references to allocators; infallible in practice as it only
+/// re-wraps the pointer.
+/// SAFETY: `this` must point to a value of type `A`.
+pub unsafe fn ref_try_clone<A>(this: *const ()) -> Result<NonNull<()>, AllocError>
+where
+    A: Allocator,
+{
+    Ok(unsafe { ref_clone::<A>(this) })
+}
+
+/// Clones the underlying allocator into a new allocation, aborting the process on allocation
+/// failure. Prefer [`default_try_clone`] where failure can be handled.
+/// SAFETY: `this` must point to a value of type `A`.
+pub unsafe fn default_clone<A>(this: *const ()) -> NonNull<()>
+where
+    A: Allocator + Clone,
+{
+    match unsafe { default_try_clone::<A>(this) } {
+        Ok(storage) => storage,
+        Err(_) => alloc::handle_alloc_error(Layout::new::<A>()),
     }
 }
 
@@ -91,6 +372,202 @@ where
     unsafe { NonNull::new_unchecked(this as *mut ()) }
 }
 
+// Inline storage.
+//
+// When `A` fits within the size and alignment of the `data: NonNull<()>` word (every ZST and
+// every pointer-sized allocator), the allocator is bit-packed into the word itself instead of
+// being heap-allocated. This eliminates the backing allocation for the common `owned(Global)`
+// case. The inline shims below reconstruct `&A` from a stack copy of the word rather than
+// dereferencing it as a pointer, and `inline_delete` drops the allocator in place without
+// deallocating.
+
+/// Returns `true` if `A` can be stored inline in the data word.
+pub const fn fits_inline<A>() -> bool {
+    mem::size_of::<A>() <= mem::size_of::<NonNull<()>>()
+        && mem::align_of::<A>() <= mem::align_of::<NonNull<()>>()
+}
+
+/// Packs an inline-eligible `A` into the data word. The word is seeded with an all-ones pattern so
+/// that a sub-word `A` leaves the bytes it does not cover non-zero and the word stays non-null.
+///
+/// Only a *word-sized* `A` overwrites every byte, so only such an allocator can make the word
+/// null, and then only if its own bit pattern is all-zero. Pointer-sized allocators in practice
+/// wrap a reference or a [`NonNull`], which is never all-zero; an all-zero word-sized allocator
+/// cannot be stored inline and is rejected here rather than producing a null `NonNull<()>`.
+///
+/// SAFETY: `A` must satisfy [`fits_inline`].
+pub unsafe fn pack_inline<A>(value: A) -> NonNull<()> {
+    let mut word = usize::MAX as *mut ();
+    // SAFETY: `A` fits within the word by the precondition, and `&mut word` is word-aligned.
+    unsafe {
+        ptr::write((&mut word as *mut *mut ()).cast::<A>(), value);
+    }
+    NonNull::new(word).expect("inline allocator must not have an all-zero representation")
+}
+
+macro_rules! inline_fwd {
+    ($name:ident => $method:ident ($($p:ident : $t:ty),*) $(-> $ret:ty)?) => {
+        /// Inline shim: reconstructs `&A` from the data word instead of dereferencing it.
+        /// SAFETY: `this` is the data word of an inline allocator of type `A`.
+        pub unsafe fn $name<A>(this: *const (), $($p: $t),*) $(-> $ret)?
+        where
+            A: Allocator,
+        {
+            let slot = this;
+            // SAFETY: `slot` holds the bytes of an inline `A`, which is valid to read because
+            //         `A` fits within and is no more aligned than the word.
+            let this = unsafe { &*(&slot as *const *const ()).cast::<A>() };
+            #[allow(unused_unsafe)]
+            unsafe { this.$method($($p),*) }
+        }
+    };
+}
+
+inline_fwd!(inline_allocate => allocate(layout: Layout) -> Result<NonNull<[u8]>, AllocError>);
+inline_fwd!(inline_allocate_zeroed => allocate_zeroed(layout: Layout) -> Result<NonNull<[u8]>, AllocError>);
+inline_fwd!(inline_deallocate => deallocate(ptr: NonNull<u8>, layout: Layout));
+inline_fwd!(inline_grow => grow(ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError>);
+inline_fwd!(inline_grow_zeroed => grow_zeroed(ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError>);
+inline_fwd!(inline_shrink => shrink(ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError>);
+
+/// Inline ownership query forwarding to the underlying [`OwningAllocator`].
+/// SAFETY: `this` is the data word of an inline allocator of type `A`.
+pub unsafe fn inline_owning_owns<A>(this: *const (), ptr: NonNull<u8>, layout: Layout) -> bool
+where
+    A: OwningAllocator,
+{
+    let slot = this;
+    // SAFETY: see `inline_fwd`.
+    let this = unsafe { &*(&slot as *const *const ()).cast::<A>() };
+    this.owns(ptr, layout)
+}
+
+/// Drops an inline-stored allocator in place. No memory is deallocated because none was
+/// allocated for it.
+/// SAFETY: `this` is the data word of an inline allocator of type `A`.
+pub unsafe fn inline_delete<A>(this: NonNull<()>)
+where
+    A: Allocator,
+{
+    let mut slot = this.as_ptr();
+    // SAFETY: `slot` holds the bytes of an inline `A`, which we drop.
+    unsafe {
+        ptr::drop_in_place((&mut slot as *mut *mut ()).cast::<A>());
+    }
+}
+
+/// Fallibly clones an inline-stored allocator. Never allocates, so it never fails.
+/// SAFETY: `this` is the data word of an inline allocator of type `A`.
+pub unsafe fn inline_try_clone<A>(this: *const ()) -> Result<NonNull<()>, AllocError>
+where
+    A: Allocator + Clone,
+{
+    let slot = this;
+    // SAFETY: see `inline_fwd`.
+    let this = unsafe { &*(&slot as *const *const ()).cast::<A>() };
+    // SAFETY: `A` fits inline, upheld by the inline vtable constructors.
+    Ok(unsafe { pack_inline(this.clone()) })
+}
+
+/// Clones an inline-stored allocator.
+/// SAFETY: `this` is the data word of an inline allocator of type `A`.
+pub unsafe fn inline_clone<A>(this: *const ()) -> NonNull<()>
+where
+    A: Allocator + Clone,
+{
+    // SAFETY: as [`inline_try_clone`], which is infallible here.
+    match unsafe { inline_try_clone::<A>(this) } {
+        Ok(storage) => storage,
+        Err(_) => alloc::handle_alloc_error(Layout::new::<A>()),
+    }
+}
+
+macro_rules! inline_c_fwd {
+    ($name:ident => $method:ident ($($p:ident : $t:ty),*)) => {
+        /// `extern "C"` inline shim: reconstructs `&A` from the data word.
+        /// SAFETY: `this` is the data word of an inline allocator of type `A`.
+        pub unsafe extern "C" fn $name<A>(this: *const (), $($p: $t),*) -> CAllocResult
+        where
+            A: Allocator,
+        {
+            let slot = this;
+            // SAFETY: see `inline_fwd`.
+            let this = unsafe { &*(&slot as *const *const ()).cast::<A>() };
+            CAllocResult::from_result(unsafe { this.$method($(c_in!($p, $p)),*) })
+        }
+    };
+}
+
+inline_c_fwd!(inline_c_allocate => allocate(layout: CLayout));
+inline_c_fwd!(inline_c_allocate_zeroed => allocate_zeroed(layout: CLayout));
+inline_c_fwd!(inline_c_grow => grow(ptr: *mut u8, old_layout: CLayout, new_layout: CLayout));
+inline_c_fwd!(inline_c_grow_zeroed => grow_zeroed(ptr: *mut u8, old_layout: CLayout, new_layout: CLayout));
+inline_c_fwd!(inline_c_shrink => shrink(ptr: *mut u8, old_layout: CLayout, new_layout: CLayout));
+
+/// `extern "C"` inline shim for `deallocate`.
+/// SAFETY: `this` is the data word of an inline allocator of type `A`.
+pub unsafe extern "C" fn inline_c_deallocate<A>(this: *const (), ptr: *mut u8, layout: CLayout)
+where
+    A: Allocator,
+{
+    let slot = this;
+    // SAFETY: see `inline_fwd`; `ptr` names a live block, so it is non-null; `layout` came from a
+    //         valid `Layout`.
+    unsafe {
+        let this = &*(&slot as *const *const ()).cast::<A>();
+        this.deallocate(NonNull::new_unchecked(ptr), layout.into_layout());
+    }
+}
+
+/// `extern "C"` inline ownership query forwarding to the underlying [`OwningAllocator`].
+/// SAFETY: `this` is the data word of an inline allocator of type `A`.
+pub unsafe extern "C" fn inline_c_owning_owns<A>(
+    this: *const (),
+    ptr: *mut u8,
+    layout: CLayout,
+) -> bool
+where
+    A: OwningAllocator,
+{
+    let slot = this;
+    // SAFETY: see `inline_fwd`; `ptr` names a candidate block, so it is non-null; `layout` came
+    //         from a valid `Layout`.
+    unsafe {
+        let this = &*(&slot as *const *const ()).cast::<A>();
+        this.owns(NonNull::new_unchecked(ptr), layout.into_layout())
+    }
+}
+
+/// `extern "C"` inline shim for `inline_delete`.
+/// SAFETY: as [`inline_delete`].
+pub unsafe extern "C" fn inline_c_delete<A>(this: *mut ())
+where
+    A: Allocator,
+{
+    // SAFETY: `this` is non-null and is the data word of an inline `A`.
+    unsafe { inline_delete::<A>(NonNull::new_unchecked(this)) }
+}
+
+/// `extern "C"` inline shim for `inline_clone`.
+/// SAFETY: as [`inline_clone`].
+pub unsafe extern "C" fn inline_c_clone<A>(this: *const ()) -> *mut ()
+where
+    A: Allocator + Clone,
+{
+    // SAFETY: `this` is the data word of an inline `A`.
+    unsafe { inline_clone::<A>(this).as_ptr() }
+}
+
+/// `extern "C"` inline shim for `inline_try_clone`; infallible, so never returns null.
+/// SAFETY: as [`inline_try_clone`].
+pub unsafe extern "C" fn inline_c_try_clone<A>(this: *const ()) -> *mut ()
+where
+    A: Allocator + Clone,
+{
+    // SAFETY: `this` is the data word of an inline `A`.
+    unsafe { inline_clone::<A>(this).as_ptr() }
+}
+
 impl RawPolyAllocVTable {
     /// Returns a reference to a vtable compatible with `A`. This vtable is usable for modeling
     /// owned allocators.
@@ -98,15 +575,20 @@ impl RawPolyAllocVTable {
     where
         A: Allocator + Clone,
     {
-        &Self {
-            allocate: allocate::<A>,
-            allocate_zeroed: allocate_zeroed::<A>,
-            deallocate: deallocate::<A>,
-            grow: grow::<A>,
-            grow_zeroed: grow_zeroed::<A>,
-            shrink: shrink::<A>,
-            delete: default_delete::<A>,
-            clone: default_clone::<A>,
+        &const {
+            Self {
+                allocate: allocate::<A>,
+                allocate_zeroed: allocate_zeroed::<A>,
+                deallocate: deallocate::<A>,
+                grow: grow::<A>,
+                grow_zeroed: grow_zeroed::<A>,
+                shrink: shrink::<A>,
+                owns: default_owns::<A>,
+                delete: default_delete::<A>,
+                clone: default_clone::<A>,
+                try_clone: default_try_clone::<A>,
+                c: CPolyAllocVTable::owned::<A>(),
+            }
         }
     }
 
@@ -116,15 +598,240 @@ impl RawPolyAllocVTable {
     where
         A: Allocator,
     {
-        &Self {
-            allocate: allocate::<A>,
-            allocate_zeroed: allocate_zeroed::<A>,
-            deallocate: deallocate::<A>,
-            grow: grow::<A>,
-            grow_zeroed: grow_zeroed::<A>,
-            shrink: shrink::<A>,
-            delete: ref_delete,
-            clone: ref_clone::<A>,
+        &const {
+            Self {
+                allocate: allocate::<A>,
+                allocate_zeroed: allocate_zeroed::<A>,
+                deallocate: deallocate::<A>,
+                grow: grow::<A>,
+                grow_zeroed: grow_zeroed::<A>,
+                shrink: shrink::<A>,
+                owns: default_owns::<A>,
+                delete: ref_delete,
+                clone: ref_clone::<A>,
+                try_clone: ref_try_clone::<A>,
+                c: CPolyAllocVTable::borrowed::<A>(),
+            }
+        }
+    }
+
+    /// Returns a reference to a vtable compatible with `A`, where `A` can answer ownership
+    /// queries precisely. Used for modeling owned [`OwningAllocator`]s so that the erased
+    /// allocator still routes deallocations correctly inside a fallback chain.
+    pub fn owning<A>() -> &'static Self
+    where
+        A: OwningAllocator + Clone,
+    {
+        &const {
+            Self {
+                allocate: allocate::<A>,
+                allocate_zeroed: allocate_zeroed::<A>,
+                deallocate: deallocate::<A>,
+                grow: grow::<A>,
+                grow_zeroed: grow_zeroed::<A>,
+                shrink: shrink::<A>,
+                owns: owning_owns::<A>,
+                delete: default_delete::<A>,
+                clone: default_clone::<A>,
+                try_clone: default_try_clone::<A>,
+                c: CPolyAllocVTable::owning::<A>(),
+            }
+        }
+    }
+
+    /// Returns a reference to a vtable compatible with a borrowed [`OwningAllocator`].
+    pub fn owning_borrowed<A>() -> &'static Self
+    where
+        A: OwningAllocator,
+    {
+        &const {
+            Self {
+                allocate: allocate::<A>,
+                allocate_zeroed: allocate_zeroed::<A>,
+                deallocate: deallocate::<A>,
+                grow: grow::<A>,
+                grow_zeroed: grow_zeroed::<A>,
+                shrink: shrink::<A>,
+                owns: owning_owns::<A>,
+                delete: ref_delete,
+                clone: ref_clone::<A>,
+                try_clone: ref_try_clone::<A>,
+                c: CPolyAllocVTable::owning_borrowed::<A>(),
+            }
+        }
+    }
+
+    /// Returns a vtable for an owned `A` stored inline in the data word. `delete` drops in place
+    /// without deallocating. `A` must satisfy [`fits_inline`].
+    pub fn inline_owned<A>() -> &'static Self
+    where
+        A: Allocator + Clone,
+    {
+        &const {
+            Self {
+                allocate: inline_allocate::<A>,
+                allocate_zeroed: inline_allocate_zeroed::<A>,
+                deallocate: inline_deallocate::<A>,
+                grow: inline_grow::<A>,
+                grow_zeroed: inline_grow_zeroed::<A>,
+                shrink: inline_shrink::<A>,
+                owns: default_owns::<A>,
+                delete: inline_delete::<A>,
+                clone: inline_clone::<A>,
+                try_clone: inline_try_clone::<A>,
+                c: CPolyAllocVTable::inline_owned::<A>(),
+            }
+        }
+    }
+
+    /// Returns a vtable for an owned [`OwningAllocator`] stored inline in the data word. `A` must
+    /// satisfy [`fits_inline`].
+    pub fn inline_owning<A>() -> &'static Self
+    where
+        A: OwningAllocator + Clone,
+    {
+        &const {
+            Self {
+                allocate: inline_allocate::<A>,
+                allocate_zeroed: inline_allocate_zeroed::<A>,
+                deallocate: inline_deallocate::<A>,
+                grow: inline_grow::<A>,
+                grow_zeroed: inline_grow_zeroed::<A>,
+                shrink: inline_shrink::<A>,
+                owns: inline_owning_owns::<A>,
+                delete: inline_delete::<A>,
+                clone: inline_clone::<A>,
+                try_clone: inline_try_clone::<A>,
+                c: CPolyAllocVTable::inline_owning::<A>(),
+            }
+        }
+    }
+}
+
+impl CPolyAllocVTable {
+    /// Returns the ABI-stable vtable for an owned `A`.
+    pub const fn owned<A>() -> &'static Self
+    where
+        A: Allocator + Clone,
+    {
+        &const {
+            Self {
+                allocate: c_allocate::<A>,
+                allocate_zeroed: c_allocate_zeroed::<A>,
+                deallocate: c_deallocate::<A>,
+                grow: c_grow::<A>,
+                grow_zeroed: c_grow_zeroed::<A>,
+                shrink: c_shrink::<A>,
+                owns: c_default_owns::<A>,
+                delete: c_default_delete::<A>,
+                clone: c_default_clone::<A>,
+                try_clone: c_default_try_clone::<A>,
+            }
+        }
+    }
+
+    /// Returns the ABI-stable vtable for a borrowed `A`.
+    pub const fn borrowed<A>() -> &'static Self
+    where
+        A: Allocator,
+    {
+        &const {
+            Self {
+                allocate: c_allocate::<A>,
+                allocate_zeroed: c_allocate_zeroed::<A>,
+                deallocate: c_deallocate::<A>,
+                grow: c_grow::<A>,
+                grow_zeroed: c_grow_zeroed::<A>,
+                shrink: c_shrink::<A>,
+                owns: c_default_owns::<A>,
+                delete: c_ref_delete,
+                clone: c_ref_clone::<A>,
+                try_clone: c_ref_try_clone::<A>,
+            }
+        }
+    }
+
+    /// Returns the ABI-stable vtable for an owned [`OwningAllocator`].
+    pub const fn owning<A>() -> &'static Self
+    where
+        A: OwningAllocator + Clone,
+    {
+        &const {
+            Self {
+                allocate: c_allocate::<A>,
+                allocate_zeroed: c_allocate_zeroed::<A>,
+                deallocate: c_deallocate::<A>,
+                grow: c_grow::<A>,
+                grow_zeroed: c_grow_zeroed::<A>,
+                shrink: c_shrink::<A>,
+                owns: c_owning_owns::<A>,
+                delete: c_default_delete::<A>,
+                clone: c_default_clone::<A>,
+                try_clone: c_default_try_clone::<A>,
+            }
+        }
+    }
+
+    /// Returns the ABI-stable vtable for a borrowed [`OwningAllocator`].
+    pub const fn owning_borrowed<A>() -> &'static Self
+    where
+        A: OwningAllocator,
+    {
+        &const {
+            Self {
+                allocate: c_allocate::<A>,
+                allocate_zeroed: c_allocate_zeroed::<A>,
+                deallocate: c_deallocate::<A>,
+                grow: c_grow::<A>,
+                grow_zeroed: c_grow_zeroed::<A>,
+                shrink: c_shrink::<A>,
+                owns: c_owning_owns::<A>,
+                delete: c_ref_delete,
+                clone: c_ref_clone::<A>,
+                try_clone: c_ref_try_clone::<A>,
+            }
+        }
+    }
+
+    /// Returns the ABI-stable vtable for an owned `A` stored inline in the data word.
+    pub const fn inline_owned<A>() -> &'static Self
+    where
+        A: Allocator + Clone,
+    {
+        &const {
+            Self {
+                allocate: inline_c_allocate::<A>,
+                allocate_zeroed: inline_c_allocate_zeroed::<A>,
+                deallocate: inline_c_deallocate::<A>,
+                grow: inline_c_grow::<A>,
+                grow_zeroed: inline_c_grow_zeroed::<A>,
+                shrink: inline_c_shrink::<A>,
+                owns: c_default_owns::<A>,
+                delete: inline_c_delete::<A>,
+                clone: inline_c_clone::<A>,
+                try_clone: inline_c_try_clone::<A>,
+            }
+        }
+    }
+
+    /// Returns the ABI-stable vtable for an owned inline [`OwningAllocator`].
+    pub const fn inline_owning<A>() -> &'static Self
+    where
+        A: OwningAllocator + Clone,
+    {
+        &const {
+            Self {
+                allocate: inline_c_allocate::<A>,
+                allocate_zeroed: inline_c_allocate_zeroed::<A>,
+                deallocate: inline_c_deallocate::<A>,
+                grow: inline_c_grow::<A>,
+                grow_zeroed: inline_c_grow_zeroed::<A>,
+                shrink: inline_c_shrink::<A>,
+                owns: inline_c_owning_owns::<A>,
+                delete: inline_c_delete::<A>,
+                clone: inline_c_clone::<A>,
+                try_clone: inline_c_try_clone::<A>,
+            }
         }
     }
 }